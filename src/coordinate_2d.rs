@@ -1,11 +1,27 @@
 use thiserror::Error;
 
-#[derive(Debug, Error, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Error, PartialEq, Eq, Clone)]
 pub enum CoordinateError {
     #[error("Latitude out of range")]
     LatitudeOutOfRange,
     #[error("Longitude out of range")]
     LongitudeOutOfRange,
+    #[error("failed to parse NMEA coordinate: {0}")]
+    NmeaParse(String),
+}
+
+// WGS84 mean radius, in metres.
+const EARTH_RADIUS_M: f64 = 6371008.8;
+
+// 1 gon = 0.9 degrees (400 gon to a full turn, instead of 360 degrees).
+const DEGREES_PER_GON: f64 = 0.9;
+
+/// Axis convention for [`Coordinate2d::to_tuple`], since different geodesy toolchains
+/// disagree on whether a coordinate pair is ordered lat/lon or lon/lat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AxisOrder {
+    LatLon,
+    LonLat,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -31,6 +47,12 @@ impl Coordinate2d {
         Self::new((lat + 90.0) / 180.0, (lon + 180.0) / 360.0)
     }
 
+    /// Create a coordinate from a latitude and longitude given in radians, symmetric with
+    /// [`Self::from_degrees`].
+    pub fn from_radians(lat: f64, lon: f64) -> Result<Self, CoordinateError> {
+        Self::from_degrees(lat.to_degrees(), lon.to_degrees())
+    }
+
     pub fn lat_deg(&self) -> f64 {
         self.lat * 180.0 - 90.0
     }
@@ -38,6 +60,125 @@ impl Coordinate2d {
     pub fn lon_deg(&self) -> f64 {
         self.lon * 360.0 - 180.0
     }
+
+    pub fn lat_rad(&self) -> f64 {
+        self.lat_deg().to_radians()
+    }
+
+    pub fn lon_rad(&self) -> f64 {
+        self.lon_deg().to_radians()
+    }
+
+    /// Latitude in gradians (gon), where 1 gon = 0.9 degrees.
+    pub fn lat_gon(&self) -> f64 {
+        self.lat_deg() / DEGREES_PER_GON
+    }
+
+    /// Longitude in gradians (gon), where 1 gon = 0.9 degrees.
+    pub fn lon_gon(&self) -> f64 {
+        self.lon_deg() / DEGREES_PER_GON
+    }
+
+    /// This coordinate's latitude and longitude in degrees, ordered according to `order`.
+    pub fn to_tuple(&self, order: AxisOrder) -> (f64, f64) {
+        match order {
+            AxisOrder::LatLon => (self.lat_deg(), self.lon_deg()),
+            AxisOrder::LonLat => (self.lon_deg(), self.lat_deg()),
+        }
+    }
+
+    /// Great-circle distance to `other`, in metres, using the haversine formula on a
+    /// WGS84 mean radius of 6,371,008.8 m.
+    pub fn distance_m(&self, other: &Coordinate2d) -> f64 {
+        let lat1 = self.lat_deg().to_radians();
+        let lat2 = other.lat_deg().to_radians();
+        let delta_lat = lat2 - lat1;
+        let delta_lon = (other.lon_deg() - self.lon_deg()).to_radians();
+
+        let h = (delta_lat / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+
+        2.0 * EARTH_RADIUS_M * h.sqrt().min(1.0).asin()
+    }
+
+    /// Initial bearing, in degrees clockwise from true north and normalised to `[0, 360)`,
+    /// along the great circle from `self` to `other`.
+    pub fn initial_bearing_deg(&self, other: &Coordinate2d) -> f64 {
+        let lat1 = self.lat_deg().to_radians();
+        let lat2 = other.lat_deg().to_radians();
+        let delta_lon = (other.lon_deg() - self.lon_deg()).to_radians();
+
+        let y = delta_lon.sin() * lat2.cos();
+        let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * delta_lon.cos();
+        let theta = y.atan2(x).to_degrees();
+
+        (theta + 360.0) % 360.0
+    }
+
+    /// The point reached by travelling `distance_m` metres along the initial `bearing_deg`
+    /// (degrees clockwise from true north), starting from `self`.
+    pub fn coord_at(
+        &self,
+        bearing_deg: f64,
+        distance_m: f64,
+    ) -> Result<Coordinate2d, CoordinateError> {
+        let delta = distance_m / EARTH_RADIUS_M;
+        let theta = bearing_deg.to_radians();
+
+        let lat1 = self.lat_deg().to_radians();
+        let lon1 = self.lon_deg().to_radians();
+
+        let lat2 = (lat1.sin() * delta.cos() + lat1.cos() * delta.sin() * theta.cos()).asin();
+        let lon2 = lon1
+            + (theta.sin() * delta.sin() * lat1.cos()).atan2(delta.cos() - lat1.sin() * lat2.sin());
+
+        // Normalise longitude into [-180, 180).
+        let lon2_deg = (lon2.to_degrees() + 180.0).rem_euclid(360.0) - 180.0;
+
+        Self::from_degrees(lat2.to_degrees(), lon2_deg)
+    }
+
+    /// Create a coordinate from NMEA-style `DDDMM.mmmm` latitude/longitude fields plus
+    /// their hemisphere letters, e.g. as emitted by a `$GPGGA` sentence.
+    pub fn from_nmea(
+        lat: &str,
+        lat_hemi: char,
+        lon: &str,
+        lon_hemi: char,
+    ) -> Result<Self, CoordinateError> {
+        let lat_deg = Self::nmea_field_to_degrees(lat, lat_hemi, ['N', 'S'])?;
+        let lon_deg = Self::nmea_field_to_degrees(lon, lon_hemi, ['E', 'W'])?;
+        Self::from_degrees(lat_deg, lon_deg)
+    }
+
+    // Converts a single `DDDMM.mmmm` NMEA field to signed decimal degrees.
+    // `hemispheres` is `[positive, negative]`, e.g. `['N', 'S']` for a latitude field.
+    fn nmea_field_to_degrees(
+        field: &str,
+        hemisphere: char,
+        hemispheres: [char; 2],
+    ) -> Result<f64, CoordinateError> {
+        if !hemispheres.contains(&hemisphere) {
+            return Err(CoordinateError::NmeaParse(format!(
+                "invalid hemisphere '{hemisphere}', expected one of {hemispheres:?}"
+            )));
+        }
+
+        let n: f64 = field
+            .parse()
+            .map_err(|_| CoordinateError::NmeaParse(format!("invalid NMEA field '{field}'")))?;
+
+        let degrees = (n / 100.0).trunc();
+        let minutes = n % 100.0;
+        let decimal_degrees = degrees + minutes / 60.0;
+
+        let sign = if hemisphere == hemispheres[1] {
+            -1.0
+        } else {
+            1.0
+        };
+        Ok(sign * decimal_degrees)
+    }
 }
 
 #[cfg(test)]
@@ -89,4 +230,94 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_distance_m() -> Result<(), CoordinateError> {
+        let a = Coordinate2d::from_degrees(0.0, 0.0)?;
+        let b = Coordinate2d::from_degrees(0.0, 0.0)?;
+        assert_eq!(a.distance_m(&b), 0.0);
+
+        // Quarter of the way around the equator.
+        let a = Coordinate2d::from_degrees(0.0, 0.0)?;
+        let b = Coordinate2d::from_degrees(0.0, 90.0)?;
+        let expected = std::f64::consts::FRAC_PI_2 * 6371008.8;
+        assert!((a.distance_m(&b) - expected).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_initial_bearing_deg() -> Result<(), CoordinateError> {
+        let a = Coordinate2d::from_degrees(0.0, 0.0)?;
+        let b = Coordinate2d::from_degrees(1.0, 0.0)?;
+        assert!((a.initial_bearing_deg(&b) - 0.0).abs() < 1e-9);
+
+        let b = Coordinate2d::from_degrees(0.0, 1.0)?;
+        assert!((a.initial_bearing_deg(&b) - 90.0).abs() < 1e-9);
+
+        let b = Coordinate2d::from_degrees(-1.0, 0.0)?;
+        assert!((a.initial_bearing_deg(&b) - 180.0).abs() < 1e-9);
+
+        let b = Coordinate2d::from_degrees(0.0, -1.0)?;
+        assert!((a.initial_bearing_deg(&b) - 270.0).abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coord_at() -> Result<(), CoordinateError> {
+        let start = Coordinate2d::from_degrees(0.0, 0.0)?;
+
+        // Travelling due north a quarter of the way around the globe lands on the pole.
+        let quarter_circumference = std::f64::consts::FRAC_PI_2 * 6371008.8;
+        let dest = start.coord_at(0.0, quarter_circumference)?;
+        assert!((dest.lat_deg() - 90.0).abs() < 1e-6);
+
+        // coord_at should be the inverse of distance_m/initial_bearing_deg.
+        let dest = start.coord_at(45.0, 100_000.0)?;
+        assert!((start.distance_m(&dest) - 100_000.0).abs() < 1e-6);
+        assert!((start.initial_bearing_deg(&dest) - 45.0).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_nmea() -> Result<(), CoordinateError> {
+        // 4807.038,N / 01131.000,E is the classic NMEA example from the GGA spec.
+        let c = Coordinate2d::from_nmea("4807.038", 'N', "01131.000", 'E')?;
+        assert!((c.lat_deg() - 48.1173).abs() < 1e-4);
+        assert!((c.lon_deg() - 11.5167).abs() < 1e-4);
+
+        let c = Coordinate2d::from_nmea("4807.038", 'S', "01131.000", 'W')?;
+        assert!((c.lat_deg() + 48.1173).abs() < 1e-4);
+        assert!((c.lon_deg() + 11.5167).abs() < 1e-4);
+
+        let err = Coordinate2d::from_nmea("4807.038", 'E', "01131.000", 'E').unwrap_err();
+        assert!(matches!(err, CoordinateError::NmeaParse(_)));
+
+        let err = Coordinate2d::from_nmea("not-a-number", 'N', "01131.000", 'E').unwrap_err();
+        assert!(matches!(err, CoordinateError::NmeaParse(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_angular_units_and_axis_order() -> Result<(), CoordinateError> {
+        let c = Coordinate2d::from_degrees(45.0, -90.0)?;
+
+        assert!((c.lat_rad() - std::f64::consts::FRAC_PI_4).abs() < 1e-9);
+        assert!((c.lon_rad() + std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+
+        assert!((c.lat_gon() - 50.0).abs() < 1e-9);
+        assert!((c.lon_gon() + 100.0).abs() < 1e-9);
+
+        assert_eq!(c.to_tuple(AxisOrder::LatLon), (c.lat_deg(), c.lon_deg()));
+        assert_eq!(c.to_tuple(AxisOrder::LonLat), (c.lon_deg(), c.lat_deg()));
+
+        let from_rad = Coordinate2d::from_radians(c.lat_rad(), c.lon_rad())?;
+        assert!((from_rad.lat_deg() - c.lat_deg()).abs() < 1e-9);
+        assert!((from_rad.lon_deg() - c.lon_deg()).abs() < 1e-9);
+
+        Ok(())
+    }
 }
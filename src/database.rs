@@ -0,0 +1,379 @@
+use super::coordinate_2d::Coordinate2d;
+use super::parser::{parse_user_header_label, ParseError};
+use super::tile::DtedTile;
+
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// The user header label is a fixed-size 80 byte record, so we can read just that much
+// to learn a file's origin without parsing the (potentially huge) elevation data behind it.
+const UHL_SIZE: usize = 80;
+
+/// Identifies a tile by the integer (lat, lon) degrees of its south-west origin.
+type TileKey = (i32, i32);
+
+// `Coordinate2d` stores angles normalised to [0, 1], so converting an exact integer degree
+// back with `lat_deg()`/`lon_deg()` can land a hair below it (e.g. `7.999999999999986`).
+// Nudging by an epsilon well below any real post spacing keeps a tile's own origin from
+// flooring into the cell south/west of it, without affecting genuinely fractional coords.
+const TILE_KEY_EPSILON: f64 = 1e-9;
+
+fn tile_key(coord: Coordinate2d) -> TileKey {
+    (
+        (coord.lat_deg() + TILE_KEY_EPSILON).floor() as i32,
+        (coord.lon_deg() + TILE_KEY_EPSILON).floor() as i32,
+    )
+}
+
+/// Default number of fully-parsed tiles kept resident in memory at once.
+const DEFAULT_CACHE_CAPACITY: usize = 16;
+
+// A tiny least-recently-used cache of parsed tiles. Keeping only a handful of tiles
+// resident bounds memory use when a query walks across many tile boundaries.
+struct TileCache {
+    capacity: usize,
+    tiles: HashMap<TileKey, DtedTile>,
+    order: VecDeque<TileKey>,
+}
+
+impl TileCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tiles: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get_or_load(&mut self, key: TileKey, path: &Path) -> Result<&DtedTile, ParseError> {
+        if self.tiles.contains_key(&key) {
+            self.order.retain(|&k| k != key);
+        } else {
+            let tile = DtedTile::from_file(path)?;
+            if self.tiles.len() >= self.capacity {
+                if let Some(evicted) = self.order.pop_front() {
+                    self.tiles.remove(&evicted);
+                }
+            }
+            self.tiles.insert(key, tile);
+        }
+        self.order.push_back(key);
+        Ok(self
+            .tiles
+            .get(&key)
+            .expect("key was just inserted or already present"))
+    }
+}
+
+/// Indexes a directory of `.dt0`/`.dt1`/`.dt2` files by their 1°×1° origin cell and
+/// resolves elevation queries across tile boundaries, parsing each tile's full data lazily
+/// (and LRU-evicting it) on demand rather than upfront.
+pub struct DtedDatabase {
+    index: HashMap<TileKey, PathBuf>,
+    cache: RefCell<TileCache>,
+}
+
+impl DtedDatabase {
+    /// Recursively scans `dir` for `.dt0`/`.dt1`/`.dt2` files and indexes them by origin,
+    /// reading only each file's 80 byte user header label rather than its full contents.
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> Result<Self, ParseError> {
+        let mut index = HashMap::new();
+        Self::scan_dir(dir.as_ref(), &mut index)?;
+        Ok(Self {
+            index,
+            cache: RefCell::new(TileCache::new(DEFAULT_CACHE_CAPACITY)),
+        })
+    }
+
+    fn scan_dir(dir: &Path, index: &mut HashMap<TileKey, PathBuf>) -> Result<(), ParseError> {
+        for entry in std::fs::read_dir(dir).map_err(ParseError::Io)? {
+            let path = entry.map_err(ParseError::Io)?.path();
+
+            if path.is_dir() {
+                Self::scan_dir(&path, index)?;
+                continue;
+            }
+
+            let is_dted_file = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| matches!(ext, "dt0" | "dt1" | "dt2"))
+                .unwrap_or(false);
+            if !is_dted_file {
+                continue;
+            }
+
+            let mut file = File::open(&path).map_err(ParseError::Io)?;
+            let mut header_bytes = [0u8; UHL_SIZE];
+            file.read_exact(&mut header_bytes).map_err(ParseError::Io)?;
+            let (_, (origin, _num_lat, _num_lon)) = parse_user_header_label(&header_bytes)
+                .map_err(|e| ParseError::Invalid(format!("{}", e)))?;
+
+            index.insert(tile_key(origin), path);
+        }
+        Ok(())
+    }
+
+    /// Resolves the elevation at `coord` by selecting the indexed tile whose origin cell
+    /// contains it and delegating to [`DtedTile::elevation_m`].
+    pub fn elevation_m(&self, coord: Coordinate2d) -> Option<i16> {
+        let path = self.index.get(&tile_key(coord))?;
+
+        let mut cache = self.cache.borrow_mut();
+        let tile = cache.get_or_load(tile_key(coord), path).ok()?;
+        tile.elevation_m(coord)
+    }
+
+    /// Origin coordinates of the indexed tiles whose 1°×1° cell overlaps the
+    /// bounding box from `min` to `max`, letting callers preload a region ahead of a batch
+    /// of queries.
+    pub fn tiles_in_bbox(&self, min: Coordinate2d, max: Coordinate2d) -> Vec<Coordinate2d> {
+        let (min_lat, min_lon) = tile_key(min);
+        let (max_lat, max_lon) = tile_key(max);
+
+        self.index
+            .keys()
+            .filter(|(lat, lon)| {
+                (min_lat..=max_lat).contains(lat) && (min_lon..=max_lon).contains(lon)
+            })
+            .map(|&(lat, lon)| {
+                Coordinate2d::from_degrees(lat as f64, lon as f64)
+                    .expect("tile origins are always within valid coordinate bounds")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Encodes an angle in the `DDDMMSSH` format `parse_angle` expects. The MMSS part is
+    // always ignored on read, so it's left as zeroes here.
+    fn encode_angle(degrees: u16, hemisphere: char) -> [u8; 8] {
+        let mut field = [0u8; 8];
+        field[0..3].copy_from_slice(format!("{degrees:03}").as_bytes());
+        field[3..7].copy_from_slice(b"0000");
+        field[7] = hemisphere as u8;
+        field
+    }
+
+    // Builds an 80 byte UHL1 record for a tile whose south-west origin is `origin`.
+    fn build_uhl(origin: Coordinate2d, num_lat: u16, num_lon: u16) -> Vec<u8> {
+        let mut record = vec![0u8; 80];
+        record[0..4].copy_from_slice(b"UHL1");
+
+        let (lon_hemi, lon_abs) = if origin.lon_deg() < 0.0 {
+            ('W', -origin.lon_deg())
+        } else {
+            ('E', origin.lon_deg())
+        };
+        let (lat_hemi, lat_abs) = if origin.lat_deg() < 0.0 {
+            ('S', -origin.lat_deg())
+        } else {
+            ('N', origin.lat_deg())
+        };
+        record[4..12].copy_from_slice(&encode_angle(lon_abs.round() as u16, lon_hemi));
+        record[12..20].copy_from_slice(&encode_angle(lat_abs.round() as u16, lat_hemi));
+        // Bytes 20..47 (lon/lat interval, accuracy, security code, unique ref) are ignored.
+        record[47..51].copy_from_slice(format!("{num_lon:04}").as_bytes());
+        record[51..55].copy_from_slice(format!("{num_lat:04}").as_bytes());
+        // Bytes 55..80 (multiple accuracy, reserved) are ignored.
+        record
+    }
+
+    // A DSI record with every field this crate reads left blank; `database.rs` only cares
+    // about the tile's origin and post counts, so the metadata content doesn't matter here.
+    fn build_dsi() -> Vec<u8> {
+        let mut record = vec![b' '; 648];
+        record[0..3].copy_from_slice(b"DSI");
+        record
+    }
+
+    fn build_acc() -> Vec<u8> {
+        let mut record = vec![b' '; 2700];
+        record[0..3].copy_from_slice(b"ACC");
+        record
+    }
+
+    // Converts a height to DTED's sign-magnitude big-endian encoding, the inverse of
+    // `parser::parse_height`.
+    fn encode_height(height: i16) -> [u8; 2] {
+        let raw: u16 = if height < 0 {
+            (-height as u16) | 0x8000
+        } else {
+            height as u16
+        };
+        raw.to_be_bytes()
+    }
+
+    // Builds the data section of a tile: `num_lon` column records, each holding `num_lat`
+    // posts all set to `elevation`, with a correct checksum.
+    fn build_data(num_lat: usize, num_lon: usize, elevation: i16) -> Vec<u8> {
+        let mut data = Vec::new();
+        for _ in 0..num_lon {
+            let header = [0u8; 8];
+            let heights: Vec<u8> = (0..num_lat)
+                .flat_map(|_| encode_height(elevation))
+                .collect();
+            let checksum: u32 = header.iter().chain(heights.iter()).map(|&b| b as u32).sum();
+
+            data.extend_from_slice(&header);
+            data.extend_from_slice(&heights);
+            data.extend_from_slice(&checksum.to_be_bytes());
+        }
+        data
+    }
+
+    // Writes a flat, single-elevation `num_lat`x`num_lon` tile named `name` into `dir`,
+    // returning its path.
+    fn write_tile(dir: &Path, name: &str, origin: Coordinate2d, elevation: i16) -> PathBuf {
+        let num_lat = 2;
+        let num_lon = 2;
+
+        let mut bytes = build_uhl(origin, num_lat as u16, num_lon as u16);
+        bytes.extend(build_dsi());
+        bytes.extend(build_acc());
+        bytes.extend(build_data(num_lat, num_lon, elevation));
+
+        let path = dir.join(format!("{name}.dt2"));
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    // A fresh, empty scratch directory for `name`, distinct per test so parallel test
+    // threads don't interfere with each other.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "spdted_database_test_{name}_{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_from_dir_indexes_and_resolves_tiles() {
+        let dir = temp_dir("indexes");
+        write_tile(
+            &dir,
+            "a",
+            Coordinate2d::from_degrees(10.0, 10.0).unwrap(),
+            100,
+        );
+        write_tile(
+            &dir,
+            "b",
+            Coordinate2d::from_degrees(10.0, 11.0).unwrap(),
+            200,
+        );
+
+        let db = DtedDatabase::from_dir(&dir).unwrap();
+
+        let inside_a = Coordinate2d::from_degrees(10.5, 10.5).unwrap();
+        let inside_b = Coordinate2d::from_degrees(10.5, 11.5).unwrap();
+        let outside = Coordinate2d::from_degrees(50.0, 50.0).unwrap();
+
+        assert_eq!(db.elevation_m(inside_a), Some(100));
+        assert_eq!(db.elevation_m(inside_b), Some(200));
+        assert_eq!(db.elevation_m(outside), None);
+    }
+
+    #[test]
+    fn test_tiles_in_bbox() {
+        let dir = temp_dir("bbox");
+        write_tile(
+            &dir,
+            "a",
+            Coordinate2d::from_degrees(10.0, 10.0).unwrap(),
+            100,
+        );
+        write_tile(
+            &dir,
+            "b",
+            Coordinate2d::from_degrees(10.0, 11.0).unwrap(),
+            200,
+        );
+        write_tile(
+            &dir,
+            "c",
+            Coordinate2d::from_degrees(20.0, 20.0).unwrap(),
+            300,
+        );
+
+        let db = DtedDatabase::from_dir(&dir).unwrap();
+
+        let mut origins: Vec<(f64, f64)> = db
+            .tiles_in_bbox(
+                Coordinate2d::from_degrees(10.0, 10.0).unwrap(),
+                Coordinate2d::from_degrees(10.0, 11.0).unwrap(),
+            )
+            .iter()
+            .map(|c| (c.lat_deg(), c.lon_deg()))
+            .collect();
+        origins.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_eq!(origins, vec![(10.0, 10.0), (10.0, 11.0)]);
+    }
+
+    #[test]
+    fn test_tiles_in_bbox_includes_max_bound_tile() {
+        // A longitude of 28.0 is one of the integer degrees whose round-trip through
+        // `Coordinate2d`'s normalised [0, 1] storage lands a hair below the original value
+        // (27.99999999999997), so the bbox's max bound must use the same epsilon-tolerant
+        // flooring as `tile_key` or this tile would be silently dropped from the result.
+        let dir = temp_dir("bbox_max_bound");
+        write_tile(
+            &dir,
+            "a",
+            Coordinate2d::from_degrees(0.0, 28.0).unwrap(),
+            100,
+        );
+
+        let db = DtedDatabase::from_dir(&dir).unwrap();
+
+        let origins = db.tiles_in_bbox(
+            Coordinate2d::from_degrees(0.0, 27.0).unwrap(),
+            Coordinate2d::from_degrees(0.0, 28.0).unwrap(),
+        );
+
+        assert_eq!(origins.len(), 1);
+        assert_eq!(tile_key(origins[0]), (0, 28));
+    }
+
+    #[test]
+    fn test_lru_eviction_drops_oldest_tile() {
+        let dir = temp_dir("lru");
+        let capacity = DEFAULT_CACHE_CAPACITY;
+
+        let mut paths = Vec::new();
+        for i in 0..=capacity {
+            let origin = Coordinate2d::from_degrees(i as f64, 0.0).unwrap();
+            paths.push(write_tile(&dir, &format!("t{i}"), origin, i as i16));
+        }
+
+        let db = DtedDatabase::from_dir(&dir).unwrap();
+        let coord_at = |i: usize| Coordinate2d::from_degrees(i as f64 + 0.5, 0.5).unwrap();
+
+        // Touch tiles 0..capacity in order, filling the cache exactly to capacity...
+        for i in 0..capacity {
+            assert_eq!(db.elevation_m(coord_at(i)), Some(i as i16));
+        }
+        // ...then touch one more tile, which should evict the least-recently-used one (0).
+        assert_eq!(db.elevation_m(coord_at(capacity)), Some(capacity as i16));
+
+        // Tile 0 was evicted, so it's no longer cached: deleting its backing file makes it
+        // unresolvable, since `elevation_m` now has to re-read the (now missing) file.
+        std::fs::remove_file(&paths[0]).unwrap();
+        assert_eq!(db.elevation_m(coord_at(0)), None);
+
+        // Tile `capacity` is still cached (it was just touched), so it keeps resolving even
+        // after its backing file disappears.
+        std::fs::remove_file(&paths[capacity]).unwrap();
+        assert_eq!(db.elevation_m(coord_at(capacity)), Some(capacity as i16));
+    }
+}
@@ -0,0 +1,9 @@
+mod coordinate_2d;
+mod database;
+mod parser;
+mod tile;
+
+pub use coordinate_2d::{AxisOrder, Coordinate2d, CoordinateError};
+pub use database::DtedDatabase;
+pub use parser::ParseError;
+pub use tile::{DtedHeader, DtedLevel, DtedMetadata, DtedTile};
@@ -1,5 +1,5 @@
 use super::coordinate_2d::Coordinate2d;
-use super::tile::{DtedHeader, DtedTile};
+use super::tile::{DtedHeader, DtedLevel, DtedMetadata, DtedTile};
 
 use ndarray::{Array2, ShapeBuilder};
 use nom::{
@@ -38,7 +38,9 @@ fn parse_angle(input: &[u8]) -> IResult<&[u8], f64> {
 }
 
 #[inline(always)]
-fn parse_user_header_label(input: &[u8]) -> IResult<&[u8], DtedHeader> {
+pub(crate) fn parse_user_header_label(
+    input: &[u8],
+) -> IResult<&[u8], (Coordinate2d, usize, usize)> {
     let (input, _) = tag("UHL1")(input)?;
     let (input, origin_lon) =
         verify(parse_angle, |lon| (-180.0..180.0).contains(lon)).parse(input)?;
@@ -54,13 +56,122 @@ fn parse_user_header_label(input: &[u8]) -> IResult<&[u8], DtedHeader> {
 
     let origin = Coordinate2d::from_degrees(origin_lat, origin_lon)
         .expect("this should not fail because we already checked the bounds");
-    let header = DtedHeader {
-        origin_sw: origin,
-        num_lat_points,
-        num_lon_points,
+
+    Ok((input, (origin, num_lat_points, num_lon_points)))
+}
+
+// Trim an ASCII field to a `&str`, treating invalid UTF-8 (shouldn't happen in a
+// well-formed DTED file) as empty.
+#[inline(always)]
+fn ascii_field(input: &[u8]) -> &str {
+    std::str::from_utf8(input).unwrap_or_default().trim()
+}
+
+// ACC accuracy fields are either a numeric value in metres, or left blank when the
+// accuracy is not available/applicable.
+#[inline(always)]
+fn parse_accuracy_value(input: &[u8]) -> Option<f64> {
+    let field = ascii_field(input);
+    if field.is_empty() {
+        return None;
+    }
+    field.parse::<f64>().ok()
+}
+
+// The subset of DSI (Data Set Identification) fields this crate cares about.
+struct DsiFields {
+    level: DtedLevel,
+    security_classification: char,
+    producer_code: String,
+    edition: String,
+    compilation_date: String,
+}
+
+#[inline(always)]
+fn parse_dsi(input: &[u8]) -> IResult<&[u8], DsiFields> {
+    const DSI_SIZE: u16 = 648;
+    let (rest, record) = take(DSI_SIZE)(input)?;
+
+    let (record, _) = tag("DSI")(record)?;
+    let (record, security) = take(1u8)(record)?;
+    let (record, _) = take(2u8)(record)?; // security control and release markings
+    let (record, _) = take(27u8)(record)?; // security handling description
+    let (record, _) = take(26u8)(record)?; // reserved
+    let (record, level) = take(5u8)(record)?; // product level, e.g. "DTED1"
+    let (record, _) = take(15u8)(record)?; // reserved
+    let (record, _) = take(8u8)(record)?; // reserved
+    let (record, _) = take(15u8)(record)?; // unique reference number
+    let (record, _) = take(8u8)(record)?; // reserved
+    let (record, edition) = take(2u8)(record)?;
+    let (record, _) = take(1u8)(record)?; // match/merge version
+    let (record, _) = take(4u8)(record)?; // maintenance date
+    let (record, _) = take(4u8)(record)?; // match/merge date
+    let (record, _) = take(4u8)(record)?; // maintenance description code
+    let (record, producer) = take(8u8)(record)?;
+    let (record, _) = take(16u8)(record)?; // reserved
+    let (record, _) = take(15u8)(record)?; // product specification
+    let (record, _) = take(2u8)(record)?; // product specification amendment
+    let (record, _) = take(4u8)(record)?; // product specification date
+    let (record, _) = take(3u8)(record)?; // vertical datum
+    let (record, _) = take(5u8)(record)?; // horizontal datum
+    let (record, _) = take(10u8)(record)?; // digitizing/collection system
+    let (_record, compilation_date) = take(4u8)(record)?;
+    // The rest of the record (geographic extents, partial cell indicator, etc.) is not
+    // needed here.
+
+    let security = ascii_field(security).chars().next().unwrap_or(' ');
+    let level = match ascii_field(level) {
+        s if s.contains('0') => DtedLevel::Dted0,
+        s if s.contains('2') => DtedLevel::Dted2,
+        _ => DtedLevel::Dted1,
     };
+    let producer_code = ascii_field(producer).to_string();
+    let edition = ascii_field(edition).to_string();
+    let compilation_date = ascii_field(compilation_date).to_string();
+
+    Ok((
+        rest,
+        DsiFields {
+            level,
+            security_classification: security,
+            producer_code,
+            edition,
+            compilation_date,
+        },
+    ))
+}
+
+// The subset of ACC (Accuracy) fields this crate cares about, in metres. `None` means the
+// tile reports the accuracy as not available/applicable.
+struct AccFields {
+    absolute_horizontal_accuracy_m: Option<f64>,
+    absolute_vertical_accuracy_m: Option<f64>,
+    relative_horizontal_accuracy_m: Option<f64>,
+    relative_vertical_accuracy_m: Option<f64>,
+}
 
-    Ok((input, header))
+#[inline(always)]
+fn parse_acc(input: &[u8]) -> IResult<&[u8], AccFields> {
+    const ACC_SIZE: u16 = 2700;
+    let (rest, record) = take(ACC_SIZE)(input)?;
+
+    let (record, _) = tag("ACC")(record)?;
+    let (record, absolute_horizontal) = take(4u8)(record)?;
+    let (record, absolute_vertical) = take(4u8)(record)?;
+    let (record, relative_horizontal) = take(4u8)(record)?;
+    let (_record, relative_vertical) = take(4u8)(record)?;
+    // The rest of the record describes accuracy outlines for accuracy subregions, which
+    // we don't need here.
+
+    Ok((
+        rest,
+        AccFields {
+            absolute_horizontal_accuracy_m: parse_accuracy_value(absolute_horizontal),
+            absolute_vertical_accuracy_m: parse_accuracy_value(absolute_vertical),
+            relative_horizontal_accuracy_m: parse_accuracy_value(relative_horizontal),
+            relative_vertical_accuracy_m: parse_accuracy_value(relative_vertical),
+        },
+    ))
 }
 
 // Convert two big endian signed magnitude bytes to a two's complement 16 bit integer
@@ -144,9 +255,28 @@ fn parse_dted_data<'a>(header: &DtedHeader, input: &'a [u8]) -> IResult<&'a [u8]
 
 #[inline(always)]
 pub fn parse_dted_tile(input: &[u8]) -> IResult<&[u8], DtedTile> {
-    let (input, header) = parse_user_header_label(input)?;
-    // Skip DSI [648] and ACC [2700] fields -> 3348 bytes
-    let (input, _) = take(3348u16)(input)?;
+    let (input, (origin_sw, num_lat_points, num_lon_points)) = parse_user_header_label(input)?;
+    let (input, dsi) = parse_dsi(input)?;
+    let (input, acc) = parse_acc(input)?;
+
+    let metadata = DtedMetadata {
+        level: dsi.level,
+        security_classification: dsi.security_classification,
+        producer_code: dsi.producer_code,
+        edition: dsi.edition,
+        compilation_date: dsi.compilation_date,
+        absolute_horizontal_accuracy_m: acc.absolute_horizontal_accuracy_m,
+        absolute_vertical_accuracy_m: acc.absolute_vertical_accuracy_m,
+        relative_horizontal_accuracy_m: acc.relative_horizontal_accuracy_m,
+        relative_vertical_accuracy_m: acc.relative_vertical_accuracy_m,
+    };
+    let header = DtedHeader {
+        origin_sw,
+        num_lat_points,
+        num_lon_points,
+        metadata,
+    };
+
     let (input, data) = parse_dted_data(&header, input)?;
     Ok((input, DtedTile { header, data }))
 }
@@ -263,6 +393,88 @@ mod test {
         assert_eq!(actual, expected);
     }
 
+    // Builds a 648 byte DSI record with the given field values, space-padded/zero-padded
+    // exactly like a real record, leaving every field this crate doesn't read at its
+    // default blank value.
+    fn build_dsi(
+        security: u8,
+        level: &[u8; 5],
+        edition: &[u8; 2],
+        producer: &[u8; 8],
+        compilation_date: &[u8; 4],
+    ) -> Vec<u8> {
+        let mut record = vec![b' '; 648];
+        record[0..3].copy_from_slice(b"DSI");
+        record[3] = security;
+        record[59..64].copy_from_slice(level);
+        record[110..112].copy_from_slice(edition);
+        record[125..133].copy_from_slice(producer);
+        record[188..192].copy_from_slice(compilation_date);
+        record
+    }
+
+    // Builds a 2700 byte ACC record with the given accuracy fields, each either a 4 byte
+    // numeric string or blank (space-padded) to mean "not available".
+    fn build_acc(
+        absolute_horizontal: &[u8; 4],
+        absolute_vertical: &[u8; 4],
+        relative_horizontal: &[u8; 4],
+        relative_vertical: &[u8; 4],
+    ) -> Vec<u8> {
+        let mut record = vec![b' '; 2700];
+        record[0..3].copy_from_slice(b"ACC");
+        record[3..7].copy_from_slice(absolute_horizontal);
+        record[7..11].copy_from_slice(absolute_vertical);
+        record[11..15].copy_from_slice(relative_horizontal);
+        record[15..19].copy_from_slice(relative_vertical);
+        record
+    }
+
+    #[test]
+    fn test_parse_dsi() {
+        let record = build_dsi(b'U', b"DTED2", b"01", b"ABCDEFGH", b"2024");
+        let (rest, fields) = parse_dsi(&record).unwrap();
+        assert_eq!(rest, &[] as &[u8]);
+        assert_eq!(fields.security_classification, 'U');
+        assert_eq!(fields.level, DtedLevel::Dted2);
+        assert_eq!(fields.edition, "01");
+        assert_eq!(fields.producer_code, "ABCDEFGH");
+        assert_eq!(fields.compilation_date, "2024");
+
+        let record = build_dsi(b'S', b"DTED0", b"  ", b"        ", b"    ");
+        let (_, fields) = parse_dsi(&record).unwrap();
+        assert_eq!(fields.security_classification, 'S');
+        assert_eq!(fields.level, DtedLevel::Dted0);
+        assert_eq!(fields.edition, "");
+        assert_eq!(fields.producer_code, "");
+        assert_eq!(fields.compilation_date, "");
+
+        // Anything that isn't clearly "DTED0"/"DTED2" (e.g. the common "DTED1", or a
+        // malformed field) is treated as DTED1.
+        let record = build_dsi(b'U', b"DTED1", b"01", b"ABCDEFGH", b"2024");
+        let (_, fields) = parse_dsi(&record).unwrap();
+        assert_eq!(fields.level, DtedLevel::Dted1);
+    }
+
+    #[test]
+    fn test_parse_acc() {
+        let record = build_acc(b"0010", b"0020", b"0030", b"0040");
+        let (rest, fields) = parse_acc(&record).unwrap();
+        assert_eq!(rest, &[] as &[u8]);
+        assert_eq!(fields.absolute_horizontal_accuracy_m, Some(10.0));
+        assert_eq!(fields.absolute_vertical_accuracy_m, Some(20.0));
+        assert_eq!(fields.relative_horizontal_accuracy_m, Some(30.0));
+        assert_eq!(fields.relative_vertical_accuracy_m, Some(40.0));
+
+        // Blank fields mean the accuracy is not available/applicable.
+        let record = build_acc(b"    ", b"0020", b"    ", b"0040");
+        let (_, fields) = parse_acc(&record).unwrap();
+        assert_eq!(fields.absolute_horizontal_accuracy_m, None);
+        assert_eq!(fields.absolute_vertical_accuracy_m, Some(20.0));
+        assert_eq!(fields.relative_horizontal_accuracy_m, None);
+        assert_eq!(fields.relative_vertical_accuracy_m, Some(40.0));
+    }
+
     #[test]
     fn test_height_parser() {
         let x = [0b00000000, 0b00000000];
@@ -10,10 +10,73 @@ use std::path::Path;
 // pretty simple. The spec is available here:
 // https://geoservice.dlr.de/web/dataguide/srtm/pdfs/SRTM-XSAR-DEM-DTED-1.1.pdf
 
+/// The DTED level (post spacing / resolution class) a tile was produced at, as recorded
+/// in its DSI record's product level field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DtedLevel {
+    Dted0,
+    Dted1,
+    Dted2,
+}
+
+/// Data provenance and quality information carried by a tile's DSI (Data Set
+/// Identification) and ACC (Accuracy) records.
+#[derive(Debug, Clone)]
+pub struct DtedMetadata {
+    pub(crate) level: DtedLevel,
+    pub(crate) security_classification: char,
+    pub(crate) producer_code: String,
+    pub(crate) edition: String,
+    pub(crate) compilation_date: String,
+    pub(crate) absolute_horizontal_accuracy_m: Option<f64>,
+    pub(crate) absolute_vertical_accuracy_m: Option<f64>,
+    pub(crate) relative_horizontal_accuracy_m: Option<f64>,
+    pub(crate) relative_vertical_accuracy_m: Option<f64>,
+}
+
+impl DtedMetadata {
+    pub fn level(&self) -> DtedLevel {
+        self.level
+    }
+
+    pub fn security_classification(&self) -> char {
+        self.security_classification
+    }
+
+    pub fn producer_code(&self) -> &str {
+        &self.producer_code
+    }
+
+    pub fn edition(&self) -> &str {
+        &self.edition
+    }
+
+    pub fn compilation_date(&self) -> &str {
+        &self.compilation_date
+    }
+
+    pub fn absolute_horizontal_accuracy_m(&self) -> Option<f64> {
+        self.absolute_horizontal_accuracy_m
+    }
+
+    pub fn absolute_vertical_accuracy_m(&self) -> Option<f64> {
+        self.absolute_vertical_accuracy_m
+    }
+
+    pub fn relative_horizontal_accuracy_m(&self) -> Option<f64> {
+        self.relative_horizontal_accuracy_m
+    }
+
+    pub fn relative_vertical_accuracy_m(&self) -> Option<f64> {
+        self.relative_vertical_accuracy_m
+    }
+}
+
 pub struct DtedHeader {
     pub(crate) origin_sw: Coordinate2d,
     pub(crate) num_lat_points: usize,
     pub(crate) num_lon_points: usize,
+    pub(crate) metadata: DtedMetadata,
 }
 
 impl DtedHeader {
@@ -28,6 +91,10 @@ impl DtedHeader {
     pub fn num_lon(&self) -> usize {
         self.num_lon_points
     }
+
+    pub fn metadata(&self) -> &DtedMetadata {
+        &self.metadata
+    }
 }
 
 pub struct DtedTile {
@@ -88,6 +155,79 @@ impl DtedTile {
         let lat_index = lat_index as usize;
         Some(self.data[[lat_index, lon_index]])
     }
+
+    /// Like [`Self::elevation_m`], but bilinearly interpolates between the four posts
+    /// surrounding `coord` instead of taking the post at the truncated (floored) index.
+    ///
+    /// Returns `None` if `coord` falls outside the tile, or if any of the four
+    /// surrounding posts is a DTED void/no-data value (`-32767`).
+    pub fn elevation_interpolated_m(&self, coord: Coordinate2d) -> Option<f64> {
+        const VOID: i16 = -32767;
+
+        if !self.contains(coord) {
+            return None;
+        }
+
+        let num_lat = self.header.num_lat();
+        let num_lon = self.header.num_lon();
+
+        let fi = (coord.lat_deg() - self.min_lat_deg()) * num_lat as f64;
+        let fj = (coord.lon_deg() - self.min_lon_deg()) * num_lon as f64;
+
+        let i = (fi.floor() as usize).min(num_lat - 1);
+        let j = (fj.floor() as usize).min(num_lon - 1);
+        let a = (fi - i as f64).clamp(0.0, 1.0);
+        let b = (fj - j as f64).clamp(0.0, 1.0);
+
+        let i1 = (i + 1).min(num_lat - 1);
+        let j1 = (j + 1).min(num_lon - 1);
+
+        let h00 = self.data[[i, j]];
+        let h10 = self.data[[i1, j]];
+        let h01 = self.data[[i, j1]];
+        let h11 = self.data[[i1, j1]];
+
+        if [h00, h10, h01, h11].contains(&VOID) {
+            return None;
+        }
+
+        let h00 = h00 as f64;
+        let h10 = h10 as f64;
+        let h01 = h01 as f64;
+        let h11 = h11 as f64;
+
+        Some(h00 * (1.0 - a) * (1.0 - b) + h10 * a * (1.0 - b) + h01 * (1.0 - a) * b + h11 * a * b)
+    }
+
+    /// Samples elevation along the straight (great-circle) line from `start` to `end`,
+    /// stepping with [`Coordinate2d::coord_at`] to take `num_samples` equally spaced samples,
+    /// the first at `start` and (for `num_samples > 1`) the last at `end`.
+    ///
+    /// Returns `(distance_from_start_m, elevation)` pairs; the elevation is `None` where
+    /// the sample falls outside this tile.
+    pub fn elevation_profile(
+        &self,
+        start: Coordinate2d,
+        end: Coordinate2d,
+        num_samples: usize,
+    ) -> Vec<(f64, Option<i16>)> {
+        let total_distance = start.distance_m(&end);
+        let bearing = start.initial_bearing_deg(&end);
+
+        (0..num_samples)
+            .map(|i| {
+                let distance = if num_samples <= 1 {
+                    0.0
+                } else {
+                    total_distance * i as f64 / (num_samples - 1) as f64
+                };
+                let sample = start
+                    .coord_at(bearing, distance)
+                    .expect("distance along a valid great circle stays within coordinate bounds");
+                (distance, self.elevation_m(sample))
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -105,6 +245,9 @@ mod test {
         assert_eq!(tile.header.origin_sw.lon_deg(), 8.0);
         assert_eq!(tile.header.num_lat_points, 3601);
         assert_eq!(tile.header.num_lon_points, 3601);
+        // n47.dt2's name follows the usual DTED naming convention, so its DSI record
+        // should report level 2.
+        assert_eq!(tile.header().metadata().level(), DtedLevel::Dted2);
 
         let coordinates = vec![
             Coordinate2d::from_degrees(47.356418477, 8.5189232237)?,
@@ -121,4 +264,43 @@ mod test {
 
         Ok(())
     }
+
+    fn test_metadata() -> DtedMetadata {
+        DtedMetadata {
+            level: DtedLevel::Dted2,
+            security_classification: 'U',
+            producer_code: String::new(),
+            edition: String::new(),
+            compilation_date: String::new(),
+            absolute_horizontal_accuracy_m: None,
+            absolute_vertical_accuracy_m: None,
+            relative_horizontal_accuracy_m: None,
+            relative_vertical_accuracy_m: None,
+        }
+    }
+
+    #[test]
+    fn test_elevation_interpolated_m_on_edge() -> Result<()> {
+        // A 5x5 tile, flat at 100m, so interpolation is trivially checkable.
+        let header = DtedHeader {
+            origin_sw: Coordinate2d::from_degrees(0.0, 0.0)?,
+            num_lat_points: 5,
+            num_lon_points: 5,
+            metadata: test_metadata(),
+        };
+        let data = Array2::from_elem((5, 5), 100i16);
+        let tile = DtedTile { header, data };
+
+        // The north-east corner is included by `contains` (an inclusive range), so the
+        // continuous index lands exactly on `num_lat`/`num_lon` and must be clamped rather
+        // than indexed out of bounds.
+        let corner = Coordinate2d::from_degrees(1.0, 1.0)?;
+        assert!(tile.contains(corner));
+        assert_eq!(tile.elevation_interpolated_m(corner), Some(100.0));
+
+        let interior = Coordinate2d::from_degrees(0.5, 0.5)?;
+        assert_eq!(tile.elevation_interpolated_m(interior), Some(100.0));
+
+        Ok(())
+    }
 }